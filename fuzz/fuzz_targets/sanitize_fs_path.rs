@@ -0,0 +1,17 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use matchit::sanitize_fs_path;
+use std::path::Path;
+
+fuzz_target!(|captured: String| {
+    let root = Path::new("/srv/static");
+
+    if let Ok(path) = sanitize_fs_path(root, &captured) {
+        assert!(
+            path.starts_with(root),
+            "escaped root: {:?} -> {:?}",
+            captured,
+            path
+        );
+    }
+});
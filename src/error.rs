@@ -22,8 +22,22 @@ pub enum InsertError {
     ///
     /// Note you can use `{{` or `}}` to escape literal brackets.
     InvalidParam,
+    /// A parameter name contained a character other than an ASCII letter, digit, or
+    /// underscore.
+    InvalidParamName {
+        /// The offending parameter name.
+        name: String,
+    },
+    /// The same parameter name was used more than once in a single route, including a
+    /// catch-all colliding with an earlier named parameter.
+    DuplicateParamName {
+        /// The repeated parameter name.
+        name: String,
+    },
     /// Catch-all parameters are only allowed at the end of a path.
     InvalidCatchAll,
+    /// A route registered more named parameters than the router can remap internally.
+    TooManyParams,
 }
 
 impl fmt::Display for InsertError {
@@ -40,10 +54,23 @@ impl fmt::Display for InsertError {
                 write!(f, "Only one parameter is allowed per path segment")
             }
             Self::InvalidParam => write!(f, "Parameters must be registered with a valid name"),
+            Self::InvalidParamName { name } => write!(
+                f,
+                "Parameter name {:?} may only contain ASCII letters, digits, and underscores",
+                name
+            ),
+            Self::DuplicateParamName { name } => write!(
+                f,
+                "Parameter name {:?} is used more than once in the same route",
+                name
+            ),
             Self::InvalidCatchAll => write!(
                 f,
                 "Catch-all parameters are only allowed at the end of a route"
             ),
+            Self::TooManyParams => {
+                write!(f, "Routes may not contain more than 26 named parameters")
+            }
         }
     }
 }
@@ -132,6 +159,49 @@ impl Deref for MergeError {
     }
 }
 
+/// An error encountered while building a [`Router`](crate::Router) from a list of externally
+/// loaded route definitions with [`Router::from_definitions`](crate::Router::from_definitions).
+///
+/// Carries the index and path of the offending definition so the caller can report a
+/// line-item diagnostic instead of a bare panic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadError<E> {
+    /// The index of the definition that failed, in the order it was provided.
+    pub index: usize,
+    /// The route pattern of the definition that failed.
+    pub path: String,
+    /// The underlying failure.
+    pub kind: LoadErrorKind<E>,
+}
+
+/// The specific failure recorded by a [`LoadError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadErrorKind<E> {
+    /// The caller-provided function failed to produce a value for this definition.
+    Bind(E),
+    /// The definition's path could not be inserted into the router.
+    Insert(InsertError),
+}
+
+impl<E: fmt::Display> fmt::Display for LoadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            LoadErrorKind::Bind(err) => write!(
+                f,
+                "route definition #{} ({}) failed to bind: {}",
+                self.index, self.path, err
+            ),
+            LoadErrorKind::Insert(err) => write!(
+                f,
+                "route definition #{} ({}) failed to register: {}",
+                self.index, self.path, err
+            ),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for LoadError<E> {}
+
 /// A failed match attempt.
 ///
 /// ```
@@ -115,13 +115,17 @@ As it turns out, this method of routing is extremely fast. See the [benchmark re
 
 mod error;
 mod escape;
+#[macro_use]
+mod macros;
 mod params;
 mod router;
+mod sanitize;
 mod tree;
 
-pub use error::{InsertError, MatchError, MergeError};
+pub use error::{InsertError, LoadError, LoadErrorKind, MatchError, MergeError};
 pub use params::{Params, ParamsIter};
-pub use router::{Match, Router};
+pub use router::{Match, RouteDefinition, Router, RouterBuilder};
+pub use sanitize::{sanitize_fs_path, sanitize_fs_path_canonical, Traversal};
 
 #[cfg(doctest)]
 mod readme {
@@ -0,0 +1,38 @@
+/// Registers many routes on a [`Router`](crate::Router) at once, so a route table reads
+/// as a single declaration instead of one statement per route.
+///
+/// Since `Router<T>` has no notion of HTTP methods, each entry is just a route pattern
+/// and the value to store under it:
+///
+/// ```rust
+/// # use matchit::{routes, Router};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut router = Router::new();
+/// routes! { router,
+///     "/" => "index",
+///     "/users/{id}" => "show_user",
+///     "/{*asset}" => "assets",
+/// }?;
+///
+/// assert_eq!(*router.at("/users/1")?.value, "show_user");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The macro expands to a chain of [`Router::insert`](crate::Router::insert) calls and
+/// evaluates to a `Result<&mut Router<_>, matchit::InsertError>`, so it can be used with `?`
+/// just like a single call would be.
+#[macro_export]
+macro_rules! routes {
+    ($router:expr, $($route:expr => $value:expr),+ $(,)?) => {
+        'routes: {
+            let router = &mut $router;
+            $(
+                if let Err(err) = router.insert($route, $value) {
+                    break 'routes Err(err);
+                }
+            )+
+            Ok(router)
+        }
+    };
+}
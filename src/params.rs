@@ -122,6 +122,25 @@ impl<'k, 'v> Params<'k, 'v> {
         }
     }
 
+    /// Inserts a key value parameter pair into the list.
+    ///
+    /// This is mainly useful for constructing a [`Params`] by hand in tests, without going
+    /// through [`Router::at`](crate::Router::at) — the borrow checker still requires `key`
+    /// and `value` to outlive the `Params`, the same as a route match would.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use matchit::Params;
+    /// let mut params = Params::new();
+    /// params.insert("id", "1");
+    ///
+    /// assert_eq!(params.get("id"), Some("1"));
+    /// ```
+    pub fn insert(&mut self, key: &'k str, value: &'v str) {
+        self.push(key.as_bytes(), value.as_bytes());
+    }
+
     /// Inserts a key value parameter pair into the list.
     pub(crate) fn push(&mut self, key: &'k [u8], value: &'v [u8]) {
         #[cold]
@@ -266,4 +285,15 @@ mod tests {
         let params = Params::new();
         assert!(params.get("").is_none());
     }
+
+    #[test]
+    fn insert_public_constructor() {
+        let mut params = Params::new();
+        params.insert("id", "1");
+        params.insert("slug", "hello-world");
+
+        assert_eq!(params.get("id"), Some("1"));
+        assert_eq!(params.get("slug"), Some("hello-world"));
+        assert_eq!(params.len(), 2);
+    }
 }
@@ -1,7 +1,9 @@
-use crate::error::MergeError;
+use crate::error::{LoadError, LoadErrorKind, MergeError};
 use crate::tree::Node;
 use crate::{InsertError, MatchError, Params};
 
+use std::fmt;
+
 /// A zero-copy URL router.
 ///
 /// See [the crate documentation](crate) for details.
@@ -130,11 +132,37 @@ impl<T> Router<T> {
         self.root.remove(path.into())
     }
 
+    /// Removes every route from the router, leaving it indistinguishable from a freshly
+    /// constructed [`Router::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use matchit::Router;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut router = Router::new();
+    /// router.insert("/home", "Welcome!")?;
+    ///
+    /// router.clear();
+    /// assert!(router.is_empty());
+    /// assert!(router.at("/home").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clear(&mut self) {
+        self.root = Node::default();
+    }
+
     #[cfg(feature = "__test_helpers")]
     pub fn check_priorities(&self) -> Result<u32, (u32, u32)> {
         self.root.check_priorities()
     }
 
+    #[cfg(feature = "__test_helpers")]
+    pub fn check_invariants(&self) -> Result<(), String> {
+        self.root.check_invariants()
+    }
+
     /// Merge a given router into current one.
     ///
     /// Returns a list of [`InsertError`] for every failed insertion.
@@ -171,6 +199,295 @@ impl<T> Router<T> {
             Err(MergeError(errors))
         }
     }
+
+    /// Mounts every route of `other` under `prefix`, which may itself contain route
+    /// parameters (e.g. `/tenants/{tenant}`).
+    ///
+    /// This is [`Router::merge`] with each of `other`'s patterns prepended with `prefix`
+    /// first, so a route like `/users/{id}` registered on `other` becomes
+    /// `/tenants/{tenant}/users/{id}` on `self`, and a request to `/tenants/acme/users/7`
+    /// yields both `tenant` and `id` in [`Params`]. Since the prefixed pattern is inserted
+    /// through the same path as any other route, a parameter name reused between `prefix`
+    /// and one of `other`'s routes is rejected the same way a duplicate parameter name in a
+    /// single pattern always is, as part of the [`MergeError`] returned. A single trailing
+    /// slash on `prefix` is trimmed before prepending, so `"/tenants/{tenant}"` and
+    /// `"/tenants/{tenant}/"` mount identically instead of producing a double slash.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use matchit::Router;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut root = Router::new();
+    ///
+    /// let mut tenant = Router::new();
+    /// tenant.insert("/users/{id}", "A User")?;
+    ///
+    /// root.mount("/tenants/{tenant}", tenant)?;
+    ///
+    /// let matched = root.at("/tenants/acme/users/7")?;
+    /// assert_eq!(matched.params.get("tenant"), Some("acme"));
+    /// assert_eq!(matched.params.get("id"), Some("7"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mount(&mut self, prefix: impl AsRef<str>, other: Self) -> Result<(), MergeError> {
+        let prefix = prefix.as_ref().strip_suffix('/').unwrap_or(prefix.as_ref());
+        let mut errors = Vec::new();
+        other.root.for_each(|path, value| {
+            if let Err(err) = self.insert(format!("{prefix}{path}"), value) {
+                errors.push(err);
+            }
+        });
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(MergeError(errors))
+        }
+    }
+
+    /// Returns the patterns of every route registered in the router, sorted alphabetically.
+    ///
+    /// Useful for golden-file or snapshot tests of a route table, since the order is stable
+    /// regardless of insertion order or the tree's internal layout.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use matchit::Router;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut router = Router::new();
+    /// router.insert("/users/{id}", "A User")?;
+    /// router.insert("/home", "Welcome!")?;
+    ///
+    /// assert_eq!(router.routes(), vec!["/home", "/users/{id}"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn routes(&self) -> Vec<String> {
+        let mut routes = self.root.routes();
+        routes.sort_unstable();
+        routes
+    }
+
+    /// Returns the number of routes registered in the router.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use matchit::Router;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut router = Router::new();
+    /// assert_eq!(router.len(), 0);
+    ///
+    /// router.insert("/home", "Welcome!")?;
+    /// router.insert("/users/{id}", "A User")?;
+    /// assert_eq!(router.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.root.len()
+    }
+
+    /// Returns `true` if no routes are registered in the router.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use matchit::Router;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut router = Router::new();
+    /// assert!(router.is_empty());
+    ///
+    /// router.insert("/home", "Welcome!")?;
+    /// assert!(!router.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a [`RouterBuilder`] for declaring a whole router in one expression.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use matchit::Router;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let router = Router::builder()
+    ///     .route("/home", "Welcome!")
+    ///     .route("/users/{id}", "A User")
+    ///     .build()?;
+    ///
+    /// assert_eq!(*router.at("/home")?.value, "Welcome!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> RouterBuilder<T> {
+        RouterBuilder::default()
+    }
+
+    /// Inserts a route and returns `&mut Self`, so route registrations can be chained.
+    ///
+    /// This is equivalent to [`Router::insert`], but returns `&mut Self` on success, so
+    /// registrations can be chained with `?` as `router.route(..)?.route(..)?`. A failed
+    /// insertion returns its error immediately, which stops the chain at that route.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use matchit::Router;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut router = Router::new();
+    /// router.route("/home", "Welcome!")?.route("/blog", "Our blog.")?;
+    ///
+    /// assert_eq!(*router.at("/blog")?.value, "Our blog.");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn route(&mut self, route: impl Into<String>, value: T) -> Result<&mut Self, InsertError> {
+        self.insert(route, value)?;
+        Ok(self)
+    }
+}
+
+/// Lists every registered route pattern, one per line, in the same order as [`Router::routes`].
+///
+/// This works for any `T`, since it's built on [`Router::routes`] rather than on the stored
+/// values themselves.
+///
+/// # Examples
+///
+/// ```rust
+/// # use matchit::Router;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut router = Router::new();
+/// router.insert("/users/{id}", "A User")?;
+/// router.insert("/home", "Welcome!")?;
+///
+/// assert_eq!(router.to_string(), "/home\n/users/{id}\n");
+/// # Ok(())
+/// # }
+/// ```
+impl<T> fmt::Display for Router<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for route in self.routes() {
+            writeln!(f, "{route}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A builder for constructing a [`Router`] with a fluent, chainable API.
+///
+/// Unlike [`Router::route`], errors are deferred until [`RouterBuilder::build`] is called,
+/// so a whole route table can be declared in one expression without an intermediate
+/// mutable binding. See [`Router::builder`].
+#[derive(Debug)]
+pub struct RouterBuilder<T> {
+    router: Router<T>,
+    error: Option<InsertError>,
+}
+
+impl<T> Default for RouterBuilder<T> {
+    fn default() -> Self {
+        Self {
+            router: Router::new(),
+            error: None,
+        }
+    }
+}
+
+impl<T> RouterBuilder<T> {
+    /// Inserts a route into the router being built.
+    ///
+    /// If insertion fails, the error is recorded and returned by [`RouterBuilder::build`];
+    /// subsequent calls to `route` become no-ops so the first error is the one reported.
+    pub fn route(mut self, route: impl Into<String>, value: T) -> Self {
+        if self.error.is_none() {
+            if let Err(err) = self.router.insert(route, value) {
+                self.error = Some(err);
+            }
+        }
+
+        self
+    }
+
+    /// Consumes the builder, returning the constructed [`Router`], or the first
+    /// [`InsertError`] encountered while registering routes.
+    pub fn build(self) -> Result<Router<T>, InsertError> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.router),
+        }
+    }
+}
+
+/// A single route definition loaded from an external source, for use with
+/// [`Router::from_definitions`].
+///
+/// `matchit` has no opinion on the source format (YAML, JSON, a database row, ...); this
+/// type only carries the route pattern and whatever caller-defined data describes the
+/// handler to bind to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteDefinition<D> {
+    /// The route pattern to register, in the same syntax accepted by [`Router::insert`].
+    pub path: String,
+    /// Caller-defined data describing the value to bind to this route.
+    pub data: D,
+}
+
+impl<T> Router<T> {
+    /// Builds a router from a list of externally loaded route definitions.
+    ///
+    /// `bind` is called once per definition to turn its caller-defined `data` into the
+    /// value stored in the router. If `bind` or the subsequent [`Router::insert`] fails,
+    /// a [`LoadError`] is returned immediately, naming the offending definition's index
+    /// and path so it can be reported as a line-item diagnostic instead of a panic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use matchit::{Router, RouteDefinition};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let defs = vec![
+    ///     RouteDefinition { path: "/home".into(), data: "home-service" },
+    ///     RouteDefinition { path: "/users/{id}".into(), data: "user-service" },
+    /// ];
+    ///
+    /// let router: Router<&str> = Router::from_definitions(defs, |name| Ok::<_, std::convert::Infallible>(*name))?;
+    /// assert_eq!(*router.at("/home")?.value, "home-service");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_definitions<D, E>(
+        defs: impl IntoIterator<Item = RouteDefinition<D>>,
+        mut bind: impl FnMut(&D) -> Result<T, E>,
+    ) -> Result<Self, LoadError<E>> {
+        let mut router = Self::new();
+
+        for (index, def) in defs.into_iter().enumerate() {
+            let value = bind(&def.data).map_err(|err| LoadError {
+                index,
+                path: def.path.clone(),
+                kind: LoadErrorKind::Bind(err),
+            })?;
+
+            router
+                .insert(def.path.clone(), value)
+                .map_err(|err| LoadError {
+                    index,
+                    path: def.path,
+                    kind: LoadErrorKind::Insert(err),
+                })?;
+        }
+
+        Ok(router)
+    }
 }
 
 /// A successful match consisting of the registered value
@@ -0,0 +1,231 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Turns a captured catch-all value (e.g. from a `{*filepath}` parameter) into a
+/// filesystem path rooted at `root`, rejecting anything that could escape it.
+///
+/// This rejects `..` segments, NUL bytes, Windows drive (`C:`) and UNC (`\\server\share`)
+/// prefixes, and backslashes, decodes percent-encoding exactly once, and collapses
+/// duplicate slashes before joining the result onto `root`. It does not touch the
+/// filesystem or follow symlinks; see [`sanitize_fs_path_canonical`] if you need the
+/// result to also be verified against symlink-based escapes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use matchit::sanitize_fs_path;
+/// # use std::path::Path;
+/// let root = Path::new("/srv/static");
+/// assert_eq!(sanitize_fs_path(root, "docs/index.html").unwrap(), root.join("docs/index.html"));
+/// assert!(sanitize_fs_path(root, "../../etc/passwd").is_err());
+/// assert!(sanitize_fs_path(root, "..%2f..%2fetc/passwd").is_err());
+/// ```
+pub fn sanitize_fs_path(root: &Path, captured: &str) -> Result<PathBuf, Traversal> {
+    if captured.as_bytes().contains(&0) {
+        return Err(Traversal);
+    }
+
+    let decoded = percent_decode(captured)?;
+
+    if decoded.contains('\\') || has_windows_prefix(&decoded) {
+        return Err(Traversal);
+    }
+
+    let mut path = root.to_path_buf();
+    for segment in decoded.split('/') {
+        match segment {
+            "" | "." => continue,
+            ".." => return Err(Traversal),
+            segment => path.push(segment),
+        }
+    }
+
+    Ok(path)
+}
+
+/// Like [`sanitize_fs_path`], but also canonicalizes the result and verifies that it
+/// is still located under the canonicalized `root`, so that symlinks inside `root`
+/// cannot be used to escape it. This requires the resulting path to exist on disk.
+pub fn sanitize_fs_path_canonical(root: &Path, captured: &str) -> Result<PathBuf, Traversal> {
+    let path = sanitize_fs_path(root, captured)?;
+
+    let root = root.canonicalize().map_err(|_| Traversal)?;
+    let resolved = path.canonicalize().map_err(|_| Traversal)?;
+
+    if resolved.starts_with(&root) {
+        Ok(resolved)
+    } else {
+        Err(Traversal)
+    }
+}
+
+fn has_windows_prefix(path: &str) -> bool {
+    path.split('/').any(|segment| {
+        let bytes = segment.as_bytes();
+        matches!(bytes.first(), Some(b) if b.is_ascii_alphabetic()) && bytes.get(1) == Some(&b':')
+    })
+}
+
+// Decodes a `%XX` percent-encoded string exactly once, leaving invalid or incomplete
+// escapes in place rather than erroring. Fails if an escape decodes to a NUL byte or
+// the decoded bytes are not valid UTF-8.
+fn percent_decode(input: &str) -> Result<String, Traversal> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = bytes.get(i + 1..i + 3);
+            if let Some(hex) = hex.filter(|hex| hex.iter().all(u8::is_ascii_hexdigit)) {
+                if let Ok(hex) = std::str::from_utf8(hex) {
+                    if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                        if byte == 0 {
+                            return Err(Traversal);
+                        }
+
+                        out.push(byte);
+                        i += 3;
+                        continue;
+                    }
+                }
+            }
+        }
+
+        out.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8(out).map_err(|_| Traversal)
+}
+
+/// Returned by [`sanitize_fs_path`] when a captured path is rejected as one that could
+/// escape its root, and by [`sanitize_fs_path_canonical`] also when the path cannot be
+/// canonicalized (for example, because it does not exist).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Traversal;
+
+impl fmt::Display for Traversal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "path would escape the root directory")
+    }
+}
+
+impl std::error::Error for Traversal {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// A scratch directory under the system temp dir, removed when dropped, so filesystem
+    /// tests clean up after themselves even if an assertion panics.
+    struct ScratchDir(PathBuf);
+
+    impl ScratchDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir()
+                .join(format!("matchit-sanitize-{name}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for ScratchDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn joins_plain_paths() {
+        let root = Path::new("/srv/static");
+        assert_eq!(
+            sanitize_fs_path(root, "docs/index.html").unwrap(),
+            root.join("docs/index.html")
+        );
+    }
+
+    #[test]
+    fn collapses_duplicate_slashes() {
+        let root = Path::new("/srv/static");
+        assert_eq!(
+            sanitize_fs_path(root, "docs//index.html").unwrap(),
+            root.join("docs/index.html")
+        );
+    }
+
+    #[test]
+    fn rejects_dotdot_segments() {
+        let root = Path::new("/srv/static");
+        assert!(sanitize_fs_path(root, "../secret").is_err());
+        assert!(sanitize_fs_path(root, "docs/../../secret").is_err());
+    }
+
+    #[test]
+    fn rejects_encoded_traversal() {
+        let root = Path::new("/srv/static");
+        assert!(sanitize_fs_path(root, "..%2f..%2fetc/passwd").is_err());
+        assert!(sanitize_fs_path(root, "%2e%2e/secret").is_err());
+        assert!(sanitize_fs_path(root, "docs/%2e%2e/%2e%2e/secret").is_err());
+    }
+
+    #[test]
+    fn rejects_nul_bytes() {
+        let root = Path::new("/srv/static");
+        assert!(sanitize_fs_path(root, "foo\0bar").is_err());
+        assert!(sanitize_fs_path(root, "foo%00bar").is_err());
+    }
+
+    #[test]
+    fn rejects_windows_prefixes_and_backslashes() {
+        let root = Path::new("/srv/static");
+        assert!(sanitize_fs_path(root, "C:/windows/system32").is_err());
+        assert!(sanitize_fs_path(root, "..\\..\\secret").is_err());
+    }
+
+    #[test]
+    fn rejects_windows_prefix_in_non_leading_segment() {
+        let root = Path::new("/srv/static");
+        assert!(sanitize_fs_path(root, "foo/C:/bar").is_err());
+        assert!(sanitize_fs_path(root, "docs/foo/D:/windows").is_err());
+    }
+
+    #[test]
+    fn canonical_resolves_existing_file_under_root() {
+        let scratch = ScratchDir::new("canonical-ok");
+        fs::write(scratch.0.join("index.html"), b"hi").unwrap();
+
+        let resolved = sanitize_fs_path_canonical(&scratch.0, "index.html").unwrap();
+        assert_eq!(
+            resolved,
+            scratch.0.canonicalize().unwrap().join("index.html")
+        );
+    }
+
+    #[test]
+    fn canonical_rejects_missing_file() {
+        let scratch = ScratchDir::new("canonical-missing");
+        assert!(sanitize_fs_path_canonical(&scratch.0, "does-not-exist").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn canonical_rejects_symlink_escape() {
+        let scratch = ScratchDir::new("canonical-symlink");
+        let secret_dir = std::env::temp_dir().join(format!(
+            "matchit-sanitize-canonical-symlink-secret-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&secret_dir);
+        fs::create_dir_all(&secret_dir).unwrap();
+        fs::write(secret_dir.join("secret.txt"), b"top secret").unwrap();
+
+        std::os::unix::fs::symlink(&secret_dir, scratch.0.join("escape")).unwrap();
+
+        let result = sanitize_fs_path_canonical(&scratch.0, "escape/secret.txt");
+        let _ = fs::remove_dir_all(&secret_dir);
+        assert!(result.is_err());
+    }
+}
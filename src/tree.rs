@@ -659,6 +659,97 @@ impl<T> Node<T> {
 
         Ok(priority)
     }
+
+    /// Test helper that ensures the tree's structural invariants hold: `indices` lines up
+    /// with the node's static children, at most one wildcard child exists and it's always
+    /// last, and only the root may have an empty prefix.
+    #[cfg(feature = "__test_helpers")]
+    pub fn check_invariants(&self) -> Result<(), String> {
+        self.check_invariants_at(&UnescapedRoute::default())
+    }
+
+    #[cfg(feature = "__test_helpers")]
+    fn check_invariants_at(&self, path: &UnescapedRoute) -> Result<(), String> {
+        let mut path = path.clone();
+        path.append(&self.prefix);
+
+        let describe =
+            |path: &UnescapedRoute| String::from_utf8_lossy(path.unescaped()).into_owned();
+
+        if self.prefix.is_empty() && self.node_type != NodeType::Root {
+            return Err(format!(
+                "non-root node has an empty prefix at {:?}",
+                describe(&path)
+            ));
+        }
+
+        if self.node_type == NodeType::CatchAll && !self.children.is_empty() {
+            return Err(format!(
+                "catch-all node has children at {:?}",
+                describe(&path)
+            ));
+        }
+
+        let wild_children = self
+            .children
+            .iter()
+            .filter(|c| c.node_type != NodeType::Static)
+            .count();
+        if wild_children > 1 {
+            return Err(format!(
+                "more than one wildcard child at {:?}",
+                describe(&path)
+            ));
+        }
+        if wild_children == 1
+            && !matches!(self.children.last(), Some(c) if c.node_type != NodeType::Static)
+        {
+            return Err(format!(
+                "wildcard child is not last at {:?}",
+                describe(&path)
+            ));
+        }
+        if self.wild_child != (wild_children == 1) {
+            return Err(format!(
+                "wild_child flag disagrees with children at {:?}",
+                describe(&path)
+            ));
+        }
+
+        let static_children = &self.children[..self.children.len() - wild_children];
+
+        // A param node's single continuation child (e.g. the `/` after `{id}` in
+        // `/{id}/posts`) is looked up directly rather than through `indices`.
+        let is_unindexed_continuation =
+            self.node_type == NodeType::Param && static_children.len() == 1;
+
+        if !is_unindexed_continuation && static_children.len() != self.indices.len() {
+            return Err(format!(
+                "indices length {} does not match {} static children at {:?}",
+                self.indices.len(),
+                static_children.len(),
+                describe(&path)
+            ));
+        }
+        if !is_unindexed_continuation {
+            for (index, child) in self.indices.iter().zip(static_children) {
+                if child.prefix.first() != Some(index) {
+                    return Err(format!(
+                        "index {:?} does not match child prefix {:?} at {:?}",
+                        *index as char,
+                        describe(&child.prefix),
+                        describe(&path)
+                    ));
+                }
+            }
+        }
+
+        for child in &self.children {
+            child.check_invariants_at(&path)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<T> Node<T> {
@@ -684,6 +775,41 @@ impl<T> Node<T> {
             }
         }
     }
+
+    /// Returns the patterns of every route registered in the tree, without consuming it.
+    pub fn routes(&self) -> Vec<String> {
+        let mut routes = Vec::new();
+        let mut queue = VecDeque::from([(self.prefix.clone(), self)]);
+
+        // Perform a BFS on the routing tree.
+        while let Some((mut prefix, node)) = queue.pop_front() {
+            denormalize_params(&mut prefix, &node.remapping);
+
+            if node.value.is_some() {
+                routes.push(String::from_utf8(prefix.unescaped().to_vec()).unwrap());
+            }
+
+            // Traverse the child nodes.
+            for child in &node.children {
+                let mut prefix = prefix.clone();
+                prefix.append(&child.prefix);
+                queue.push_back((prefix, child));
+            }
+        }
+
+        routes
+    }
+
+    /// Returns the number of routes registered in the tree.
+    pub fn len(&self) -> usize {
+        let mut len = usize::from(self.value.is_some());
+
+        for child in &self.children {
+            len += child.len();
+        }
+
+        len
+    }
 }
 
 /// An ordered list of route parameters keys for a specific route.
@@ -704,6 +830,10 @@ fn normalize_params(
     let mut start = 0;
     let mut original = ParamRemapping::new();
 
+    // Names of every parameter seen so far in this route (including catch-alls), used
+    // to reject duplicates such as `/orgs/{id}/repos/{id}`.
+    let mut seen = Vec::new();
+
     // Parameter names are normalized alphabetically.
     let mut next = b'a';
 
@@ -726,28 +856,46 @@ fn normalize_params(
         // We don't need to normalize catch-all parameters, as they are always
         // at the end of a route.
         if path[wildcard.clone()][1] == b'*' {
+            let name = path[wildcard.start + 2..wildcard.end - 1].to_vec();
+            reject_duplicate(&mut seen, name)?;
+
             start = wildcard.end;
             continue;
         }
 
+        if next > b'z' {
+            return Err(InsertError::TooManyParams);
+        }
+
         // Normalize the parameter.
         let removed = path.splice(wildcard.clone(), vec![b'{', next, b'}']);
 
         // Preserve the original name for remapping.
         let mut removed = removed.skip(1).collect::<Vec<_>>();
         removed.pop();
+
+        reject_duplicate(&mut seen, removed.clone())?;
         original.push(removed);
 
         next += 1;
-        if next > b'z' {
-            panic!("Too many route parameters.");
-        }
 
         // Continue the search after the parameter we just normalized.
         start = wildcard.start + 3;
     }
 }
 
+/// Records `name` as seen, failing if it was already used earlier in the same route.
+fn reject_duplicate(seen: &mut Vec<Vec<u8>>, name: Vec<u8>) -> Result<(), InsertError> {
+    if seen.contains(&name) {
+        return Err(InsertError::DuplicateParamName {
+            name: String::from_utf8_lossy(&name).into_owned(),
+        });
+    }
+
+    seen.push(name);
+    Ok(())
+}
+
 /// Restores `route` to it's original, denormalized form.
 pub(crate) fn denormalize_params(route: &mut UnescapedRoute, params: &ParamRemapping) {
     let mut start = 0;
@@ -818,6 +966,24 @@ fn find_wildcard(path: UnescapedRef<'_>) -> Result<Option<Range<usize>>, InsertE
                         }
                     }
 
+                    // Parameter names may only contain ASCII letters, digits, underscores,
+                    // and escaped brace characters (ignoring the leading `*` of a
+                    // catch-all).
+                    let mut name_start = start + 1;
+                    if path.get(name_start) == Some(&b'*') {
+                        name_start += 1;
+                    }
+                    for j in name_start..i {
+                        let c = path[j];
+                        if path.is_escaped(j) || c.is_ascii_alphanumeric() || c == b'_' {
+                            continue;
+                        }
+
+                        return Err(InsertError::InvalidParamName {
+                            name: String::from_utf8_lossy(&path[name_start..i]).into_owned(),
+                        });
+                    }
+
                     return Ok(Some(start..i + 1));
                 }
                 // `*` and `/` are invalid in parameter names.
@@ -0,0 +1,56 @@
+use matchit::{InsertError, Router};
+
+#[test]
+fn builder_chains_routes() {
+    let router = Router::builder()
+        .route("/home", "Welcome!")
+        .route("/users/{id}", "A User")
+        .build()
+        .unwrap();
+
+    assert_eq!(*router.at("/home").unwrap().value, "Welcome!");
+    assert_eq!(*router.at("/users/1").unwrap().value, "A User");
+}
+
+#[test]
+fn builder_reports_first_conflict() {
+    let err = Router::builder()
+        .route("/foo/{name}", "a")
+        .route("/foo/{other}", "b")
+        .route("/foo/{third}", "c")
+        .build()
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        InsertError::Conflict {
+            with: "/foo/{name}".into()
+        }
+    );
+}
+
+#[test]
+fn route_chains_on_mut_router() {
+    let mut router = Router::new();
+    router
+        .route("/home", "Welcome!")
+        .unwrap()
+        .route("/blog", "Our blog.")
+        .unwrap();
+
+    assert_eq!(*router.at("/home").unwrap().value, "Welcome!");
+    assert_eq!(*router.at("/blog").unwrap().value, "Our blog.");
+}
+
+#[test]
+fn route_returns_err_on_conflict() {
+    let mut router = Router::new();
+    router.route("/foo/{name}", "a").unwrap();
+
+    assert_eq!(
+        router.route("/foo/{other}", "b").unwrap_err(),
+        InsertError::Conflict {
+            with: "/foo/{name}".into()
+        }
+    );
+}
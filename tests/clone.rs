@@ -0,0 +1,15 @@
+use matchit::Router;
+
+#[test]
+fn clone_is_independent() {
+    let mut original = Router::new();
+    original.insert("/home", "Welcome!").unwrap();
+    original.insert("/users/{id}", "A User").unwrap();
+
+    let mut cloned = original.clone();
+    cloned.insert("/blog", "Our blog.").unwrap();
+    cloned.remove("/home");
+
+    assert_eq!(original.routes(), vec!["/home", "/users/{id}"]);
+    assert_eq!(cloned.routes(), vec!["/blog", "/users/{id}"]);
+}
@@ -0,0 +1,70 @@
+use matchit::{LoadErrorKind, RouteDefinition, Router};
+
+#[test]
+fn builds_router_from_definitions() {
+    let defs = vec![
+        RouteDefinition {
+            path: "/home".into(),
+            data: "home-service",
+        },
+        RouteDefinition {
+            path: "/users/{id}".into(),
+            data: "user-service",
+        },
+    ];
+
+    let router: Router<&str> =
+        Router::from_definitions(defs, |name| Ok::<_, std::convert::Infallible>(*name)).unwrap();
+
+    assert_eq!(*router.at("/home").unwrap().value, "home-service");
+    assert_eq!(*router.at("/users/1").unwrap().value, "user-service");
+}
+
+#[test]
+fn reports_bind_failure_with_index_and_path() {
+    let defs = vec![
+        RouteDefinition {
+            path: "/ok".into(),
+            data: "known",
+        },
+        RouteDefinition {
+            path: "/missing".into(),
+            data: "unknown",
+        },
+    ];
+
+    let err = Router::<&str>::from_definitions(defs, |name| {
+        if *name == "known" {
+            Ok(*name)
+        } else {
+            Err(format!("no upstream named {name:?}"))
+        }
+    })
+    .unwrap_err();
+
+    assert_eq!(err.index, 1);
+    assert_eq!(err.path, "/missing");
+    assert!(matches!(err.kind, LoadErrorKind::Bind(_)));
+}
+
+#[test]
+fn reports_conflicting_pattern_with_index_and_path() {
+    let defs = vec![
+        RouteDefinition {
+            path: "/foo/{name}".into(),
+            data: "a",
+        },
+        RouteDefinition {
+            path: "/foo/{other}".into(),
+            data: "b",
+        },
+    ];
+
+    let err =
+        Router::from_definitions(defs, |data: &&str| Ok::<_, std::convert::Infallible>(*data))
+            .unwrap_err();
+
+    assert_eq!(err.index, 1);
+    assert_eq!(err.path, "/foo/{other}");
+    assert!(matches!(err.kind, LoadErrorKind::Insert(_)));
+}
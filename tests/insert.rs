@@ -117,6 +117,60 @@ fn unnamed_param() {
     .run()
 }
 
+#[test]
+fn invalid_param_name() {
+    fn invalid(name: &str) -> InsertError {
+        InsertError::InvalidParamName { name: name.into() }
+    }
+
+    InsertTest(vec![
+        ("/users/{user id}", Err(invalid("user id"))),
+        ("/users/{user-id}", Err(invalid("user-id"))),
+        ("/users/{user.id}", Err(invalid("user.id"))),
+        ("/src/{*file path}", Err(invalid("file path"))),
+        // still valid: letters, digits, and underscores.
+        ("/users/{user_id_2}", Ok(())),
+    ])
+    .run()
+}
+
+#[test]
+fn duplicate_param_name() {
+    fn duplicate(name: &str) -> InsertError {
+        InsertError::DuplicateParamName { name: name.into() }
+    }
+
+    InsertTest(vec![
+        ("/orgs/{id}/repos/{id}", Err(duplicate("id"))),
+        ("/orgs/{id}/repos/{*id}", Err(duplicate("id"))),
+        // different names are fine.
+        ("/orgs/{id}/repos/{repo}", Ok(())),
+    ])
+    .run()
+}
+
+#[test]
+fn too_many_params() {
+    let at_limit = (0..26).map(|i| format!("/{{p{i}}}")).collect::<String>();
+    let over_limit = (0..27).map(|i| format!("/{{p{i}}}")).collect::<String>();
+    let request = (0..26).map(|i| format!("/{i}")).collect::<String>();
+
+    let mut router = Router::new();
+    router.insert(&at_limit, "ok").unwrap();
+    assert_eq!(
+        router.insert(&over_limit, "ok"),
+        Err(InsertError::TooManyParams)
+    );
+
+    let matched = router.at(&request).unwrap();
+    for i in 0..26 {
+        assert_eq!(
+            matched.params.get(format!("p{i}")),
+            Some(i.to_string().as_str())
+        );
+    }
+}
+
 #[test]
 fn double_params() {
     InsertTest(vec![
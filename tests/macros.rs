@@ -0,0 +1,28 @@
+use matchit::{routes, Router};
+
+#[test]
+fn routes_macro_registers_all_routes() {
+    let mut router = Router::new();
+    routes! { router,
+        "/" => "index",
+        "/users/{id}" => "show_user",
+        "/{*asset}" => "assets",
+    }
+    .unwrap();
+
+    assert_eq!(*router.at("/").unwrap().value, "index");
+    assert_eq!(*router.at("/users/1").unwrap().value, "show_user");
+    assert_eq!(*router.at("/static/app.js").unwrap().value, "assets");
+}
+
+#[test]
+fn routes_macro_propagates_conflicts() {
+    let mut router = Router::new();
+    let err = routes! { router,
+        "/foo/{name}" => "a",
+        "/foo/{other}" => "b",
+    }
+    .unwrap_err();
+
+    assert!(err.to_string().contains("/foo/{name}"));
+}
@@ -79,6 +79,7 @@ impl MatchTest {
         }
 
         router.check_priorities().unwrap();
+        router.check_invariants().unwrap();
 
         for (path, route, params) in self.matches {
             match router.at(path) {
@@ -63,3 +63,59 @@ fn merge_nested() {
     assert_eq!(root.at("/foo").map(|m| *m.value), Ok("foo"));
     assert_eq!(root.at("/foo/bar").map(|m| *m.value), Ok("bar"));
 }
+
+#[test]
+fn mount_with_param_prefix() {
+    let mut root = Router::new();
+
+    let mut tenant = Router::new();
+    assert!(tenant.insert("/users/{id}", "A User").is_ok());
+    assert!(tenant.insert("/home", "Welcome!").is_ok());
+
+    assert!(root.mount("/tenants/{tenant}", tenant).is_ok());
+
+    let matched = root.at("/tenants/acme/users/7").unwrap();
+    assert_eq!(*matched.value, "A User");
+    assert_eq!(matched.params.get("tenant"), Some("acme"));
+    assert_eq!(matched.params.get("id"), Some("7"));
+
+    let matched = root.at("/tenants/acme/home").unwrap();
+    assert_eq!(*matched.value, "Welcome!");
+    assert_eq!(matched.params.get("tenant"), Some("acme"));
+}
+
+#[test]
+fn mount_trims_trailing_slash_on_prefix() {
+    let mut with_slash = Router::new();
+    let mut tenant = Router::new();
+    assert!(tenant.insert("/users/{id}", "A User").is_ok());
+    assert!(with_slash.mount("/tenants/{tenant}/", tenant).is_ok());
+
+    let mut without_slash = Router::new();
+    let mut tenant = Router::new();
+    assert!(tenant.insert("/users/{id}", "A User").is_ok());
+    assert!(without_slash.mount("/tenants/{tenant}", tenant).is_ok());
+
+    assert_eq!(with_slash.routes(), without_slash.routes());
+
+    let matched = with_slash.at("/tenants/acme/users/7").unwrap();
+    assert_eq!(*matched.value, "A User");
+    assert_eq!(matched.params.get("id"), Some("7"));
+}
+
+#[test]
+fn mount_duplicate_param_name() {
+    let mut root = Router::new();
+
+    let mut tenant = Router::new();
+    assert!(tenant.insert("/users/{tenant}", "A User").is_ok());
+
+    let errors = root.mount("/tenants/{tenant}", tenant).unwrap_err();
+
+    assert_eq!(
+        errors.first(),
+        Some(&InsertError::DuplicateParamName {
+            name: "tenant".into()
+        })
+    );
+}
@@ -0,0 +1,61 @@
+use matchit::Router;
+
+#[test]
+fn routes_sorted_and_stable() {
+    let mut router = Router::new();
+    router.insert("/users/{id}", "A User").unwrap();
+    router.insert("/home", "Welcome!").unwrap();
+    router.insert("/src/{*filepath}", "A File").unwrap();
+
+    assert_eq!(
+        router.routes(),
+        vec!["/home", "/src/{*filepath}", "/users/{id}"]
+    );
+}
+
+#[test]
+fn routes_excludes_intermediate_nodes() {
+    let mut router = Router::new();
+    router.insert("/cmd/{tool}/{sub}", "Sub").unwrap();
+    router.insert("/cmd/vet", "Vet").unwrap();
+
+    // `/cmd/` itself was never registered, so it shouldn't appear.
+    assert_eq!(router.routes(), vec!["/cmd/vet", "/cmd/{tool}/{sub}"]);
+}
+
+#[test]
+fn empty_router_has_no_routes() {
+    let router: Router<()> = Router::new();
+    assert!(router.routes().is_empty());
+}
+
+#[test]
+fn len_and_is_empty() {
+    let mut router = Router::new();
+    assert_eq!(router.len(), 0);
+    assert!(router.is_empty());
+
+    router.insert("/home", "Welcome!").unwrap();
+    router.insert("/users/{id}", "A User").unwrap();
+    assert_eq!(router.len(), 2);
+    assert!(!router.is_empty());
+
+    router.remove("/home");
+    assert_eq!(router.len(), 1);
+}
+
+#[test]
+fn clear_resets_to_empty() {
+    let mut router = Router::new();
+    router.insert("/home", "Welcome!").unwrap();
+    router.insert("/users/{id}", "A User").unwrap();
+
+    router.clear();
+
+    assert!(router.is_empty());
+    assert!(router.at("/home").is_err());
+
+    // the router is fully usable afterward
+    router.insert("/home", "Welcome!").unwrap();
+    assert_eq!(router.at("/home").map(|m| *m.value), Ok("Welcome!"));
+}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use matchit::Router;
+
+// `Router<T>` places no bounds on `T`, so a handler shared via `Arc` (or any other smart
+// pointer) already works as a route value with no special support from the router.
+#[test]
+fn arc_value_is_shared_across_routes() {
+    let handler = Arc::new("shared handler");
+
+    let mut router = Router::new();
+    router.insert("/a", handler.clone()).unwrap();
+    router.insert("/b", handler.clone()).unwrap();
+    router.insert("/c", handler.clone()).unwrap();
+
+    for path in ["/a", "/b", "/c"] {
+        let matched = router.at(path).unwrap();
+        assert!(Arc::ptr_eq(matched.value, &handler));
+    }
+}